@@ -5,11 +5,14 @@
 //
 
 use std::fs::File;
-use std::io::Read;
-use std::path::Path;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
 
 use crate::{NetworkExt, NetworksExt, NetworksIter};
-use std::collections::{hash_map, HashMap};
+use std::collections::{hash_map, HashMap, HashSet};
+
+/// The sysfs directory `Networks::new` reads interface counters from.
+const DEFAULT_SYSFS_NET_ROOT: &str = "/sys/class/net/";
 
 /// Network interfaces.
 ///
@@ -21,6 +24,7 @@ use std::collections::{hash_map, HashMap};
 /// ```
 pub struct Networks {
     interfaces: HashMap<String, NetworkData>,
+    sysfs_root: PathBuf,
 }
 
 macro_rules! old_and_new {
@@ -54,9 +58,243 @@ fn read<P: AsRef<Path>>(parent: P, path: &str, data: &mut Vec<u8>) -> u64 {
 
 impl Networks {
     pub(crate) fn new() -> Self {
+        Networks::with_sysfs_root(Path::new(DEFAULT_SYSFS_NET_ROOT))
+    }
+
+    /// Builds a `Networks` that reads interface counters from `sysfs_root`
+    /// instead of the real `/sys/class/net/`. Useful to read a container's
+    /// network namespace through a bind-mounted `/sys`, to point at a
+    /// mounted snapshot for offline analysis, or to run deterministic
+    /// integration tests without patching globals.
+    ///
+    /// Using a non-default root also disables the `/proc/net/dev` fast path
+    /// normally used by [`NetworksExt::refresh`], since that file lives
+    /// outside of `sysfs_root` and would otherwise silently read the host's
+    /// live counters instead of the ones under `sysfs_root`.
+    pub fn with_sysfs_root<P: Into<PathBuf>>(sysfs_root: P) -> Self {
         Networks {
             interfaces: HashMap::new(),
+            sysfs_root: sysfs_root.into(),
+        }
+    }
+
+    /// Sums the counters of every known interface into a single snapshot,
+    /// for callers that want one "total network throughput" figure instead
+    /// of iterating and summing manually. Passing `true` for
+    /// `exclude_loopback` skips the `lo` interface, which otherwise
+    /// double-counts local traffic.
+    pub fn get_aggregated_data(&self, exclude_loopback: bool) -> AggregatedNetworkData {
+        let mut aggregated = AggregatedNetworkData::default();
+
+        for (name, data) in &self.interfaces {
+            if exclude_loopback && name == "lo" {
+                continue;
+            }
+            aggregated.rx_bytes += data.rx_bytes;
+            aggregated.old_rx_bytes += data.old_rx_bytes;
+            aggregated.tx_bytes += data.tx_bytes;
+            aggregated.old_tx_bytes += data.old_tx_bytes;
+            aggregated.rx_packets += data.rx_packets;
+            aggregated.old_rx_packets += data.old_rx_packets;
+            aggregated.tx_packets += data.tx_packets;
+            aggregated.old_tx_packets += data.old_tx_packets;
+            aggregated.rx_errors += data.rx_errors;
+            aggregated.old_rx_errors += data.old_rx_errors;
+            aggregated.tx_errors += data.tx_errors;
+            aggregated.old_tx_errors += data.old_tx_errors;
+            aggregated.rx_dropped += data.rx_dropped;
+            aggregated.old_rx_dropped += data.old_rx_dropped;
+            aggregated.tx_dropped += data.tx_dropped;
+            aggregated.old_tx_dropped += data.old_tx_dropped;
+            aggregated.rx_fifo_errors += data.rx_fifo_errors;
+            aggregated.old_rx_fifo_errors += data.old_rx_fifo_errors;
+            aggregated.tx_fifo_errors += data.tx_fifo_errors;
+            aggregated.old_tx_fifo_errors += data.old_tx_fifo_errors;
+            aggregated.rx_frame_errors += data.rx_frame_errors;
+            aggregated.old_rx_frame_errors += data.old_rx_frame_errors;
+            aggregated.tx_carrier_errors += data.tx_carrier_errors;
+            aggregated.old_tx_carrier_errors += data.old_tx_carrier_errors;
+            aggregated.collisions += data.collisions;
+            aggregated.old_collisions += data.old_collisions;
+            aggregated.multicast += data.multicast;
+            aggregated.old_multicast += data.old_multicast;
+            aggregated.rx_compressed += data.rx_compressed;
+            aggregated.old_rx_compressed += data.old_rx_compressed;
+            aggregated.tx_compressed += data.tx_compressed;
+            aggregated.old_tx_compressed += data.old_tx_compressed;
         }
+
+        aggregated
+    }
+}
+
+/// System-wide network counters obtained by summing every interface's
+/// [`NetworkData`], see [`Networks::get_aggregated_data`].
+#[derive(Default)]
+pub struct AggregatedNetworkData {
+    rx_bytes: u64,
+    old_rx_bytes: u64,
+    tx_bytes: u64,
+    old_tx_bytes: u64,
+    rx_packets: u64,
+    old_rx_packets: u64,
+    tx_packets: u64,
+    old_tx_packets: u64,
+    rx_errors: u64,
+    old_rx_errors: u64,
+    tx_errors: u64,
+    old_tx_errors: u64,
+    rx_dropped: u64,
+    old_rx_dropped: u64,
+    tx_dropped: u64,
+    old_tx_dropped: u64,
+    rx_fifo_errors: u64,
+    old_rx_fifo_errors: u64,
+    tx_fifo_errors: u64,
+    old_tx_fifo_errors: u64,
+    rx_frame_errors: u64,
+    old_rx_frame_errors: u64,
+    tx_carrier_errors: u64,
+    old_tx_carrier_errors: u64,
+    collisions: u64,
+    old_collisions: u64,
+    multicast: u64,
+    old_multicast: u64,
+    rx_compressed: u64,
+    old_rx_compressed: u64,
+    tx_compressed: u64,
+    old_tx_compressed: u64,
+}
+
+impl NetworkExt for AggregatedNetworkData {
+    fn get_received(&self) -> u64 {
+        self.rx_bytes.saturating_sub(self.old_rx_bytes)
+    }
+
+    fn get_total_received(&self) -> u64 {
+        self.rx_bytes
+    }
+
+    fn get_transmitted(&self) -> u64 {
+        self.tx_bytes.saturating_sub(self.old_tx_bytes)
+    }
+
+    fn get_total_transmitted(&self) -> u64 {
+        self.tx_bytes
+    }
+
+    fn get_packets_received(&self) -> u64 {
+        self.rx_packets.saturating_sub(self.old_rx_packets)
+    }
+
+    fn get_total_packets_received(&self) -> u64 {
+        self.rx_packets
+    }
+
+    fn get_packets_transmitted(&self) -> u64 {
+        self.tx_packets.saturating_sub(self.old_tx_packets)
+    }
+
+    fn get_total_packets_transmitted(&self) -> u64 {
+        self.tx_packets
+    }
+
+    fn get_errors_on_received(&self) -> u64 {
+        self.rx_errors.saturating_sub(self.old_rx_errors)
+    }
+
+    fn get_total_errors_on_received(&self) -> u64 {
+        self.rx_errors
+    }
+
+    fn get_errors_on_transmitted(&self) -> u64 {
+        self.tx_errors.saturating_sub(self.old_tx_errors)
+    }
+
+    fn get_total_errors_on_transmitted(&self) -> u64 {
+        self.tx_errors
+    }
+
+    fn get_dropped_received(&self) -> u64 {
+        self.rx_dropped.saturating_sub(self.old_rx_dropped)
+    }
+
+    fn get_total_dropped_received(&self) -> u64 {
+        self.rx_dropped
+    }
+
+    fn get_dropped_transmitted(&self) -> u64 {
+        self.tx_dropped.saturating_sub(self.old_tx_dropped)
+    }
+
+    fn get_total_dropped_transmitted(&self) -> u64 {
+        self.tx_dropped
+    }
+
+    fn get_fifo_errors_on_received(&self) -> u64 {
+        self.rx_fifo_errors.saturating_sub(self.old_rx_fifo_errors)
+    }
+
+    fn get_total_fifo_errors_on_received(&self) -> u64 {
+        self.rx_fifo_errors
+    }
+
+    fn get_fifo_errors_on_transmitted(&self) -> u64 {
+        self.tx_fifo_errors.saturating_sub(self.old_tx_fifo_errors)
+    }
+
+    fn get_total_fifo_errors_on_transmitted(&self) -> u64 {
+        self.tx_fifo_errors
+    }
+
+    fn get_frame_errors_on_received(&self) -> u64 {
+        self.rx_frame_errors
+            .saturating_sub(self.old_rx_frame_errors)
+    }
+
+    fn get_total_frame_errors_on_received(&self) -> u64 {
+        self.rx_frame_errors
+    }
+
+    fn get_carrier_errors_on_transmitted(&self) -> u64 {
+        self.tx_carrier_errors
+            .saturating_sub(self.old_tx_carrier_errors)
+    }
+
+    fn get_total_carrier_errors_on_transmitted(&self) -> u64 {
+        self.tx_carrier_errors
+    }
+
+    fn get_collisions(&self) -> u64 {
+        self.collisions.saturating_sub(self.old_collisions)
+    }
+
+    fn get_total_collisions(&self) -> u64 {
+        self.collisions
+    }
+
+    fn get_multicast(&self) -> u64 {
+        self.multicast.saturating_sub(self.old_multicast)
+    }
+
+    fn get_total_multicast(&self) -> u64 {
+        self.multicast
+    }
+
+    fn get_compressed_received(&self) -> u64 {
+        self.rx_compressed.saturating_sub(self.old_rx_compressed)
+    }
+
+    fn get_total_compressed_received(&self) -> u64 {
+        self.rx_compressed
+    }
+
+    fn get_compressed_transmitted(&self) -> u64 {
+        self.tx_compressed.saturating_sub(self.old_tx_compressed)
+    }
+
+    fn get_total_compressed_transmitted(&self) -> u64 {
+        self.tx_compressed
     }
 }
 
@@ -83,8 +321,16 @@ fn refresh_networks_list_from_sysfs(
             let tx_packets = read(parent, "tx_packets", &mut data);
             let rx_errors = read(parent, "rx_errors", &mut data);
             let tx_errors = read(parent, "tx_errors", &mut data);
-            // let rx_compressed = read(parent, "rx_compressed", &mut data);
-            // let tx_compressed = read(parent, "tx_compressed", &mut data);
+            let rx_dropped = read(parent, "rx_dropped", &mut data);
+            let tx_dropped = read(parent, "tx_dropped", &mut data);
+            let rx_fifo_errors = read(parent, "rx_fifo_errors", &mut data);
+            let tx_fifo_errors = read(parent, "tx_fifo_errors", &mut data);
+            let rx_frame_errors = read(parent, "rx_frame_errors", &mut data);
+            let tx_carrier_errors = read(parent, "tx_carrier_errors", &mut data);
+            let collisions = read(parent, "collisions", &mut data);
+            let multicast = read(parent, "multicast", &mut data);
+            let rx_compressed = read(parent, "rx_compressed", &mut data);
+            let tx_compressed = read(parent, "tx_compressed", &mut data);
             match interfaces.entry(entry) {
                 hash_map::Entry::Occupied(mut e) => {
                     let mut interface = e.get_mut();
@@ -94,8 +340,16 @@ fn refresh_networks_list_from_sysfs(
                     old_and_new!(interface, tx_packets, old_tx_packets);
                     old_and_new!(interface, rx_errors, old_rx_errors);
                     old_and_new!(interface, tx_errors, old_tx_errors);
-                    // old_and_new!(e, rx_compressed, old_rx_compressed);
-                    // old_and_new!(e, tx_compressed, old_tx_compressed);
+                    old_and_new!(interface, rx_dropped, old_rx_dropped);
+                    old_and_new!(interface, tx_dropped, old_tx_dropped);
+                    old_and_new!(interface, rx_fifo_errors, old_rx_fifo_errors);
+                    old_and_new!(interface, tx_fifo_errors, old_tx_fifo_errors);
+                    old_and_new!(interface, rx_frame_errors, old_rx_frame_errors);
+                    old_and_new!(interface, tx_carrier_errors, old_tx_carrier_errors);
+                    old_and_new!(interface, collisions, old_collisions);
+                    old_and_new!(interface, multicast, old_multicast);
+                    old_and_new!(interface, rx_compressed, old_rx_compressed);
+                    old_and_new!(interface, tx_compressed, old_tx_compressed);
                     interface.updated = true;
                 }
                 hash_map::Entry::Vacant(e) => {
@@ -112,10 +366,26 @@ fn refresh_networks_list_from_sysfs(
                         old_rx_errors: rx_errors,
                         tx_errors,
                         old_tx_errors: tx_errors,
-                        // rx_compressed,
-                        // old_rx_compressed: rx_compressed,
-                        // tx_compressed,
-                        // old_tx_compressed: tx_compressed,
+                        rx_dropped,
+                        old_rx_dropped: rx_dropped,
+                        tx_dropped,
+                        old_tx_dropped: tx_dropped,
+                        rx_fifo_errors,
+                        old_rx_fifo_errors: rx_fifo_errors,
+                        tx_fifo_errors,
+                        old_tx_fifo_errors: tx_fifo_errors,
+                        rx_frame_errors,
+                        old_rx_frame_errors: rx_frame_errors,
+                        tx_carrier_errors,
+                        old_tx_carrier_errors: tx_carrier_errors,
+                        collisions,
+                        old_collisions: collisions,
+                        multicast,
+                        old_multicast: multicast,
+                        rx_compressed,
+                        old_rx_compressed: rx_compressed,
+                        tx_compressed,
+                        old_tx_compressed: tx_compressed,
                         updated: true,
                     });
                 }
@@ -127,21 +397,97 @@ fn refresh_networks_list_from_sysfs(
     }
 }
 
+/// Updates the counters of already known interfaces from a single read of
+/// `/proc/net/dev`, which is far cheaper than the one-`open`-per-counter
+/// sysfs path used by [`NetworkData::update`]. Unlike
+/// `refresh_networks_list_from_sysfs`, this does not add or remove
+/// interfaces: it only touches entries already present in `interfaces`.
+///
+/// Returns the set of interface names it was able to update, so that the
+/// caller can fall back to the sysfs reader for the rest (e.g. on kernels
+/// whose `/proc/net/dev` predates some of the trailing columns).
+fn refresh_from_proc_net_dev<R: BufRead>(
+    interfaces: &mut HashMap<String, NetworkData>,
+    reader: R,
+) -> HashSet<String> {
+    let mut updated = HashSet::new();
+
+    for line in reader.lines().skip(2).map_while(Result::ok) {
+        let mut split = line.splitn(2, ':');
+        let name = match split.next() {
+            Some(name) => name.trim(),
+            None => continue,
+        };
+        let counters = match split.next() {
+            Some(counters) => counters,
+            None => continue,
+        };
+        let values: Vec<u64> = counters
+            .split_whitespace()
+            .filter_map(|v| v.parse().ok())
+            .collect();
+        if values.len() != 16 {
+            continue;
+        }
+
+        if let Some(interface) = interfaces.get_mut(name) {
+            old_and_new!(interface, rx_bytes, old_rx_bytes, values[0]);
+            old_and_new!(interface, rx_packets, old_rx_packets, values[1]);
+            old_and_new!(interface, rx_errors, old_rx_errors, values[2]);
+            old_and_new!(interface, rx_dropped, old_rx_dropped, values[3]);
+            old_and_new!(interface, rx_fifo_errors, old_rx_fifo_errors, values[4]);
+            old_and_new!(interface, rx_frame_errors, old_rx_frame_errors, values[5]);
+            old_and_new!(interface, rx_compressed, old_rx_compressed, values[6]);
+            old_and_new!(interface, multicast, old_multicast, values[7]);
+            old_and_new!(interface, tx_bytes, old_tx_bytes, values[8]);
+            old_and_new!(interface, tx_packets, old_tx_packets, values[9]);
+            old_and_new!(interface, tx_errors, old_tx_errors, values[10]);
+            old_and_new!(interface, tx_dropped, old_tx_dropped, values[11]);
+            old_and_new!(interface, tx_fifo_errors, old_tx_fifo_errors, values[12]);
+            old_and_new!(interface, collisions, old_collisions, values[13]);
+            old_and_new!(
+                interface,
+                tx_carrier_errors,
+                old_tx_carrier_errors,
+                values[14]
+            );
+            old_and_new!(interface, tx_compressed, old_tx_compressed, values[15]);
+            updated.insert(name.to_string());
+        }
+    }
+
+    updated
+}
+
 impl NetworksExt for Networks {
     fn iter(&self) -> NetworksIter {
         NetworksIter::new(self.interfaces.iter())
     }
 
     fn refresh(&mut self) {
-        let mut v = vec![0; 30];
+        let is_default_root = self.sysfs_root == Path::new(DEFAULT_SYSFS_NET_ROOT);
+        let updated = if is_default_root {
+            match File::open("/proc/net/dev") {
+                Ok(file) => refresh_from_proc_net_dev(&mut self.interfaces, BufReader::new(file)),
+                Err(_) => HashSet::new(),
+            }
+        } else {
+            HashSet::new()
+        };
+
+        if updated.len() != self.interfaces.len() {
+            let mut v = vec![0; 30];
 
-        for (interface_name, data) in self.interfaces.iter_mut() {
-            data.update(interface_name, &mut v);
+            for (interface_name, data) in self.interfaces.iter_mut() {
+                if !updated.contains(interface_name) {
+                    data.update(&self.sysfs_root, interface_name, &mut v);
+                }
+            }
         }
     }
 
     fn refresh_networks_list(&mut self) {
-        refresh_networks_list_from_sysfs(&mut self.interfaces, Path::new("/sys/class/net/"));
+        refresh_networks_list_from_sysfs(&mut self.interfaces, &self.sysfs_root);
     }
 }
 
@@ -167,23 +513,48 @@ pub struct NetworkData {
     /// similar to `rx_errors`
     tx_errors: u64,
     old_tx_errors: u64,
-    // /// Indicates the number of compressed packets received by this
-    // /// network device. This value might only be relevant for interfaces
-    // /// that support packet compression (e.g: PPP).
-    // rx_compressed: usize,
-    // old_rx_compressed: usize,
-    // /// Indicates the number of transmitted compressed packets. Note
-    // /// this might only be relevant for devices that support
-    // /// compression (e.g: PPP).
-    // tx_compressed: usize,
-    // old_tx_compressed: usize,
+    /// Total number of incoming packets dropped, usually because the
+    /// ring buffer was full.
+    rx_dropped: u64,
+    old_rx_dropped: u64,
+    /// similar to `rx_dropped`
+    tx_dropped: u64,
+    old_tx_dropped: u64,
+    /// Number of FIFO errors on received packets.
+    rx_fifo_errors: u64,
+    old_rx_fifo_errors: u64,
+    /// similar to `rx_fifo_errors`
+    tx_fifo_errors: u64,
+    old_tx_fifo_errors: u64,
+    /// Number of received packets with frame alignment errors.
+    rx_frame_errors: u64,
+    old_rx_frame_errors: u64,
+    /// Number of transmitted packets lost because of carrier errors.
+    tx_carrier_errors: u64,
+    old_tx_carrier_errors: u64,
+    /// Number of collisions during packet transmission.
+    collisions: u64,
+    old_collisions: u64,
+    /// Number of multicast packets received.
+    multicast: u64,
+    old_multicast: u64,
+    /// Indicates the number of compressed packets received by this
+    /// network device. This value might only be relevant for interfaces
+    /// that support packet compression (e.g: PPP).
+    rx_compressed: u64,
+    old_rx_compressed: u64,
+    /// Indicates the number of transmitted compressed packets. Note
+    /// this might only be relevant for devices that support
+    /// compression (e.g: PPP).
+    tx_compressed: u64,
+    old_tx_compressed: u64,
     /// Whether or not the above data has been updated during refresh
     updated: bool,
 }
 
 impl NetworkData {
-    fn update(&mut self, path: &str, data: &mut Vec<u8>) {
-        let path = &Path::new("/sys/class/net/").join(path).join("statistics");
+    fn update(&mut self, sysfs_root: &Path, interface_name: &str, data: &mut Vec<u8>) {
+        let path = &sysfs_root.join(interface_name).join("statistics");
         old_and_new!(self, rx_bytes, old_rx_bytes, read(path, "rx_bytes", data));
         old_and_new!(self, tx_bytes, old_tx_bytes, read(path, "tx_bytes", data));
         old_and_new!(
@@ -210,18 +581,66 @@ impl NetworkData {
             old_tx_errors,
             read(path, "tx_errors", data)
         );
-        // old_and_new!(
-        //     self,
-        //     rx_compressed,
-        //     old_rx_compressed,
-        //     read(path, "rx_compressed", data)
-        // );
-        // old_and_new!(
-        //     self,
-        //     tx_compressed,
-        //     old_tx_compressed,
-        //     read(path, "tx_compressed", data)
-        // );
+        old_and_new!(
+            self,
+            rx_dropped,
+            old_rx_dropped,
+            read(path, "rx_dropped", data)
+        );
+        old_and_new!(
+            self,
+            tx_dropped,
+            old_tx_dropped,
+            read(path, "tx_dropped", data)
+        );
+        old_and_new!(
+            self,
+            rx_fifo_errors,
+            old_rx_fifo_errors,
+            read(path, "rx_fifo_errors", data)
+        );
+        old_and_new!(
+            self,
+            tx_fifo_errors,
+            old_tx_fifo_errors,
+            read(path, "tx_fifo_errors", data)
+        );
+        old_and_new!(
+            self,
+            rx_frame_errors,
+            old_rx_frame_errors,
+            read(path, "rx_frame_errors", data)
+        );
+        old_and_new!(
+            self,
+            tx_carrier_errors,
+            old_tx_carrier_errors,
+            read(path, "tx_carrier_errors", data)
+        );
+        old_and_new!(
+            self,
+            collisions,
+            old_collisions,
+            read(path, "collisions", data)
+        );
+        old_and_new!(
+            self,
+            multicast,
+            old_multicast,
+            read(path, "multicast", data)
+        );
+        old_and_new!(
+            self,
+            rx_compressed,
+            old_rx_compressed,
+            read(path, "rx_compressed", data)
+        );
+        old_and_new!(
+            self,
+            tx_compressed,
+            old_tx_compressed,
+            read(path, "tx_compressed", data)
+        );
     }
 }
 
@@ -273,13 +692,139 @@ impl NetworkExt for NetworkData {
     fn get_total_errors_on_transmitted(&self) -> u64 {
         self.tx_errors
     }
+
+    fn get_dropped_received(&self) -> u64 {
+        self.rx_dropped.saturating_sub(self.old_rx_dropped)
+    }
+
+    fn get_total_dropped_received(&self) -> u64 {
+        self.rx_dropped
+    }
+
+    fn get_dropped_transmitted(&self) -> u64 {
+        self.tx_dropped.saturating_sub(self.old_tx_dropped)
+    }
+
+    fn get_total_dropped_transmitted(&self) -> u64 {
+        self.tx_dropped
+    }
+
+    fn get_fifo_errors_on_received(&self) -> u64 {
+        self.rx_fifo_errors.saturating_sub(self.old_rx_fifo_errors)
+    }
+
+    fn get_total_fifo_errors_on_received(&self) -> u64 {
+        self.rx_fifo_errors
+    }
+
+    fn get_fifo_errors_on_transmitted(&self) -> u64 {
+        self.tx_fifo_errors.saturating_sub(self.old_tx_fifo_errors)
+    }
+
+    fn get_total_fifo_errors_on_transmitted(&self) -> u64 {
+        self.tx_fifo_errors
+    }
+
+    fn get_frame_errors_on_received(&self) -> u64 {
+        self.rx_frame_errors
+            .saturating_sub(self.old_rx_frame_errors)
+    }
+
+    fn get_total_frame_errors_on_received(&self) -> u64 {
+        self.rx_frame_errors
+    }
+
+    fn get_carrier_errors_on_transmitted(&self) -> u64 {
+        self.tx_carrier_errors
+            .saturating_sub(self.old_tx_carrier_errors)
+    }
+
+    fn get_total_carrier_errors_on_transmitted(&self) -> u64 {
+        self.tx_carrier_errors
+    }
+
+    fn get_collisions(&self) -> u64 {
+        self.collisions.saturating_sub(self.old_collisions)
+    }
+
+    fn get_total_collisions(&self) -> u64 {
+        self.collisions
+    }
+
+    fn get_multicast(&self) -> u64 {
+        self.multicast.saturating_sub(self.old_multicast)
+    }
+
+    fn get_total_multicast(&self) -> u64 {
+        self.multicast
+    }
+
+    fn get_compressed_received(&self) -> u64 {
+        self.rx_compressed.saturating_sub(self.old_rx_compressed)
+    }
+
+    fn get_total_compressed_received(&self) -> u64 {
+        self.rx_compressed
+    }
+
+    fn get_compressed_transmitted(&self) -> u64 {
+        self.tx_compressed.saturating_sub(self.old_tx_compressed)
+    }
+
+    fn get_total_compressed_transmitted(&self) -> u64 {
+        self.tx_compressed
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::refresh_networks_list_from_sysfs;
+    use super::{
+        refresh_from_proc_net_dev, refresh_networks_list_from_sysfs, NetworkData, Networks,
+        DEFAULT_SYSFS_NET_ROOT,
+    };
+    use crate::{NetworkExt, NetworksExt};
     use std::collections::HashMap;
     use std::fs;
+    use std::io::Cursor;
+    use std::path::PathBuf;
+
+    fn empty_network_data() -> NetworkData {
+        NetworkData {
+            rx_bytes: 0,
+            old_rx_bytes: 0,
+            tx_bytes: 0,
+            old_tx_bytes: 0,
+            rx_packets: 0,
+            old_rx_packets: 0,
+            tx_packets: 0,
+            old_tx_packets: 0,
+            rx_errors: 0,
+            old_rx_errors: 0,
+            tx_errors: 0,
+            old_tx_errors: 0,
+            rx_dropped: 0,
+            old_rx_dropped: 0,
+            tx_dropped: 0,
+            old_tx_dropped: 0,
+            rx_fifo_errors: 0,
+            old_rx_fifo_errors: 0,
+            tx_fifo_errors: 0,
+            old_tx_fifo_errors: 0,
+            rx_frame_errors: 0,
+            old_rx_frame_errors: 0,
+            tx_carrier_errors: 0,
+            old_tx_carrier_errors: 0,
+            collisions: 0,
+            old_collisions: 0,
+            multicast: 0,
+            old_multicast: 0,
+            rx_compressed: 0,
+            old_rx_compressed: 0,
+            tx_compressed: 0,
+            old_tx_compressed: 0,
+            updated: true,
+        }
+    }
 
     #[test]
     fn refresh_networks_list_add_interface() {
@@ -321,4 +866,85 @@ mod test {
         refresh_networks_list_from_sysfs(&mut interfaces, sys_net_dir.path());
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf2"]);
     }
+
+    #[test]
+    fn refresh_from_proc_net_dev_updates_known_interfaces() {
+        let proc_net_dev = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo:    1000       10    0    0    0     0          0         0     1000      10    0    0    0     0       0          0
+  eth0:    4189       30    1    2    3     4          5         6     3636      39    7    8    9    10      11         12
+";
+
+        let mut interfaces = HashMap::new();
+        interfaces.insert("eth0".to_string(), empty_network_data());
+
+        let updated = refresh_from_proc_net_dev(&mut interfaces, Cursor::new(proc_net_dev));
+
+        assert_eq!(updated, ["eth0".to_string()].into_iter().collect());
+        let eth0 = &interfaces["eth0"];
+        assert_eq!(eth0.rx_bytes, 4189);
+        assert_eq!(eth0.tx_packets, 39);
+        assert_eq!(eth0.collisions, 10);
+        assert_eq!(eth0.tx_carrier_errors, 11);
+        assert_eq!(eth0.tx_compressed, 12);
+    }
+
+    #[test]
+    fn refresh_from_proc_net_dev_ignores_unknown_interfaces() {
+        let proc_net_dev = "\
+Inter-|   Receive                                                |  Transmit
+ face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed
+    lo:       0        0    0    0    0     0          0         0        0       0    0    0    0     0       0          0
+";
+
+        let mut interfaces = HashMap::new();
+
+        let updated = refresh_from_proc_net_dev(&mut interfaces, Cursor::new(proc_net_dev));
+
+        assert!(updated.is_empty());
+        assert!(interfaces.is_empty());
+    }
+
+    #[test]
+    fn get_aggregated_data_excludes_loopback_when_asked() {
+        let mut lo = empty_network_data();
+        lo.rx_bytes = 100;
+        lo.tx_bytes = 100;
+        let mut eth0 = empty_network_data();
+        eth0.rx_bytes = 10;
+        eth0.old_rx_bytes = 4;
+        eth0.tx_bytes = 20;
+        eth0.collisions = 3;
+        eth0.rx_dropped = 2;
+
+        let mut interfaces = HashMap::new();
+        interfaces.insert("lo".to_string(), lo);
+        interfaces.insert("eth0".to_string(), eth0);
+        let networks = Networks {
+            interfaces,
+            sysfs_root: PathBuf::from(DEFAULT_SYSFS_NET_ROOT),
+        };
+
+        let with_loopback = networks.get_aggregated_data(false);
+        assert_eq!(with_loopback.get_total_received(), 110);
+
+        let without_loopback = networks.get_aggregated_data(true);
+        assert_eq!(without_loopback.get_total_received(), 10);
+        assert_eq!(without_loopback.get_total_transmitted(), 20);
+        assert_eq!(without_loopback.get_received(), 6);
+        assert_eq!(without_loopback.get_total_collisions(), 3);
+        assert_eq!(without_loopback.get_total_dropped_received(), 2);
+    }
+
+    #[test]
+    fn with_sysfs_root_is_used_by_refresh_networks_list() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        fs::create_dir(sys_net_dir.path().join("itf1")).expect("failed to create subdirectory");
+
+        let mut networks = Networks::with_sysfs_root(sys_net_dir.path());
+        networks.refresh_networks_list();
+
+        assert_eq!(networks.interfaces.keys().collect::<Vec<_>>(), ["itf1"]);
+    }
 }
@@ -0,0 +1,193 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2019 Guillaume Gomez
+//
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+/// Protocol-level IP/TCP/UDP statistics read from `/proc/net/snmp`.
+///
+/// ```no_run
+/// use sysinfo::{System, SystemExt};
+///
+/// let s = System::new_all();
+/// let stats = s.get_protocol_stats();
+/// println!("UDP receive buffer errors: {}", stats.udp_rcvbuf_errors());
+/// ```
+pub struct ProtocolStats {
+    stats: HashMap<String, HashMap<String, u64>>,
+    old_stats: HashMap<String, HashMap<String, u64>>,
+}
+
+/// The protocol sections kept when parsing `/proc/net/snmp`; every other
+/// section is skipped. Typed getters exist for `Tcp` and `Udp` counters only.
+const TRACKED_PROTOCOLS: &[&str] = &["Ip", "Tcp", "Udp"];
+
+fn parse_proc_net_snmp<R: BufRead>(reader: R) -> HashMap<String, HashMap<String, u64>> {
+    let mut stats = HashMap::new();
+    let mut pending_header: Option<(String, Vec<String>)> = None;
+
+    for line in reader.lines().map_while(Result::ok) {
+        let mut tokens = line.split_whitespace();
+        let label = match tokens.next() {
+            Some(label) => label.trim_end_matches(':').to_string(),
+            None => continue,
+        };
+        if !TRACKED_PROTOCOLS.contains(&label.as_str()) {
+            pending_header = None;
+            continue;
+        }
+        let rest: Vec<&str> = tokens.collect();
+
+        match pending_header.take() {
+            Some((header_label, headers)) if header_label == label => {
+                let values = headers
+                    .iter()
+                    .zip(rest.iter())
+                    .filter_map(|(header, value)| {
+                        value
+                            .parse::<u64>()
+                            .ok()
+                            .map(|value| (header.clone(), value))
+                    })
+                    .collect();
+                stats.insert(label, values);
+            }
+            _ => pending_header = Some((label, rest.into_iter().map(String::from).collect())),
+        }
+    }
+    stats
+}
+
+impl Default for ProtocolStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProtocolStats {
+    pub fn new() -> Self {
+        ProtocolStats {
+            stats: HashMap::new(),
+            old_stats: HashMap::new(),
+        }
+    }
+
+    /// Re-reads `/proc/net/snmp` and rotates the previous snapshot into the
+    /// `old_*`-style deltas returned by the getters below.
+    pub fn refresh(&mut self) {
+        if let Ok(file) = File::open("/proc/net/snmp") {
+            let new_stats = parse_proc_net_snmp(BufReader::new(file));
+            self.old_stats = std::mem::replace(&mut self.stats, new_stats);
+        }
+    }
+
+    fn counter(&self, proto: &str, field: &str) -> u64 {
+        self.stats
+            .get(proto)
+            .and_then(|fields| fields.get(field))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn old_counter(&self, proto: &str, field: &str) -> u64 {
+        self.old_stats
+            .get(proto)
+            .and_then(|fields| fields.get(field))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn delta(&self, proto: &str, field: &str) -> u64 {
+        self.counter(proto, field)
+            .saturating_sub(self.old_counter(proto, field))
+    }
+
+    /// Number of UDP datagrams received with an error, since the last refresh.
+    pub fn udp_in_errors(&self) -> u64 {
+        self.delta("Udp", "InErrors")
+    }
+
+    /// Total number of UDP datagrams received with an error.
+    pub fn total_udp_in_errors(&self) -> u64 {
+        self.counter("Udp", "InErrors")
+    }
+
+    /// Number of UDP datagrams dropped because of a full receive buffer,
+    /// since the last refresh.
+    pub fn udp_rcvbuf_errors(&self) -> u64 {
+        self.delta("Udp", "RcvbufErrors")
+    }
+
+    /// Total number of UDP datagrams dropped because of a full receive buffer.
+    pub fn total_udp_rcvbuf_errors(&self) -> u64 {
+        self.counter("Udp", "RcvbufErrors")
+    }
+
+    /// Number of UDP datagrams dropped because of a full send buffer, since
+    /// the last refresh.
+    pub fn udp_sndbuf_errors(&self) -> u64 {
+        self.delta("Udp", "SndbufErrors")
+    }
+
+    /// Total number of UDP datagrams dropped because of a full send buffer.
+    pub fn total_udp_sndbuf_errors(&self) -> u64 {
+        self.counter("Udp", "SndbufErrors")
+    }
+
+    /// Number of TCP segments retransmitted, since the last refresh.
+    pub fn tcp_retrans_segs(&self) -> u64 {
+        self.delta("Tcp", "RetransSegs")
+    }
+
+    /// Total number of TCP segments retransmitted.
+    pub fn total_tcp_retrans_segs(&self) -> u64 {
+        self.counter("Tcp", "RetransSegs")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_proc_net_snmp;
+    use std::io::Cursor;
+
+    const SAMPLE: &str = "\
+Ip: Forwarding DefaultTTL InReceives InHdrErrors
+Ip: 2 64 2976 0
+Icmp: InMsgs InErrors
+Icmp: 0 0
+Tcp: RtoAlgorithm RtoMin RtoMax MaxConn RetransSegs
+Tcp: 1 200 120000 -1 7
+Udp: InDatagrams NoPorts InErrors RcvbufErrors SndbufErrors
+Udp: 2 0 3 4 5
+";
+
+    #[test]
+    fn parses_tracked_protocols() {
+        let stats = parse_proc_net_snmp(Cursor::new(SAMPLE));
+
+        assert_eq!(stats["Udp"]["InErrors"], 3);
+        assert_eq!(stats["Udp"]["RcvbufErrors"], 4);
+        assert_eq!(stats["Udp"]["SndbufErrors"], 5);
+        assert_eq!(stats["Tcp"]["RetransSegs"], 7);
+        assert_eq!(stats["Ip"]["InReceives"], 2976);
+    }
+
+    #[test]
+    fn ignores_untracked_protocols() {
+        let stats = parse_proc_net_snmp(Cursor::new(SAMPLE));
+
+        assert!(!stats.contains_key("Icmp"));
+    }
+
+    #[test]
+    fn skips_unparseable_columns() {
+        let stats = parse_proc_net_snmp(Cursor::new(SAMPLE));
+
+        // `MaxConn` is `-1` in the sample and does not fit in a `u64`.
+        assert!(!stats["Tcp"].contains_key("MaxConn"));
+    }
+}
@@ -0,0 +1,35 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2019 Guillaume Gomez
+//
+
+use crate::ProtocolStats;
+
+/// Protocol-level statistics owned by `System`, see
+/// [`System::get_protocol_stats`].
+pub struct System {
+    protocol_stats: ProtocolStats,
+}
+
+impl System {
+    pub(crate) fn new() -> Self {
+        System {
+            protocol_stats: ProtocolStats::new(),
+        }
+    }
+
+    /// Returns the protocol-level (IP/TCP/UDP) statistics parsed from
+    /// `/proc/net/snmp` by the last call to `refresh_protocol_stats`.
+    /// `System::new` does not read the file, so every getter returns `0`
+    /// until the first refresh.
+    pub fn get_protocol_stats(&self) -> &ProtocolStats {
+        &self.protocol_stats
+    }
+
+    /// Re-reads `/proc/net/snmp` into the statistics returned by
+    /// [`System::get_protocol_stats`].
+    pub(crate) fn refresh_protocol_stats(&mut self) {
+        self.protocol_stats.refresh();
+    }
+}
@@ -0,0 +1,130 @@
+//
+// Sysinfo
+//
+// Copyright (c) 2019 Guillaume Gomez
+//
+
+/// Contains all the methods of the [`NetworkData`][crate::NetworkData] struct.
+///
+/// ```no_run
+/// use sysinfo::{NetworksExt, NetworkExt, System, SystemExt};
+///
+/// let s = System::new_all();
+/// for (interface_name, data) in s.get_networks() {
+///     println!("[{}] {} bytes received", interface_name, data.get_received());
+/// }
+/// ```
+pub trait NetworkExt {
+    /// Returns the number of received bytes since the last refresh.
+    fn get_received(&self) -> u64;
+    /// Returns the total number of received bytes.
+    fn get_total_received(&self) -> u64;
+    /// Returns the number of transmitted bytes since the last refresh.
+    fn get_transmitted(&self) -> u64;
+    /// Returns the total number of transmitted bytes.
+    fn get_total_transmitted(&self) -> u64;
+    /// Returns the number of incoming packets since the last refresh.
+    fn get_packets_received(&self) -> u64;
+    /// Returns the total number of incoming packets.
+    fn get_total_packets_received(&self) -> u64;
+    /// Returns the number of outcoming packets since the last refresh.
+    fn get_packets_transmitted(&self) -> u64;
+    /// Returns the total number of outcoming packets.
+    fn get_total_packets_transmitted(&self) -> u64;
+    /// Returns the number of incoming errors since the last refresh.
+    fn get_errors_on_received(&self) -> u64;
+    /// Returns the total number of incoming errors.
+    fn get_total_errors_on_received(&self) -> u64;
+    /// Returns the number of outcoming errors since the last refresh.
+    fn get_errors_on_transmitted(&self) -> u64;
+    /// Returns the total number of outcoming errors.
+    fn get_total_errors_on_transmitted(&self) -> u64;
+
+    /// Returns the number of incoming packets dropped since the last refresh.
+    ///
+    /// Defaults to `0` so that implementors that predate this counter keep
+    /// building.
+    fn get_dropped_received(&self) -> u64 {
+        0
+    }
+    /// Returns the total number of incoming packets dropped.
+    fn get_total_dropped_received(&self) -> u64 {
+        0
+    }
+    /// Returns the number of outcoming packets dropped since the last refresh.
+    fn get_dropped_transmitted(&self) -> u64 {
+        0
+    }
+    /// Returns the total number of outcoming packets dropped.
+    fn get_total_dropped_transmitted(&self) -> u64 {
+        0
+    }
+    /// Returns the number of incoming FIFO errors since the last refresh.
+    fn get_fifo_errors_on_received(&self) -> u64 {
+        0
+    }
+    /// Returns the total number of incoming FIFO errors.
+    fn get_total_fifo_errors_on_received(&self) -> u64 {
+        0
+    }
+    /// Returns the number of outcoming FIFO errors since the last refresh.
+    fn get_fifo_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+    /// Returns the total number of outcoming FIFO errors.
+    fn get_total_fifo_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+    /// Returns the number of incoming frame alignment errors since the last
+    /// refresh.
+    fn get_frame_errors_on_received(&self) -> u64 {
+        0
+    }
+    /// Returns the total number of incoming frame alignment errors.
+    fn get_total_frame_errors_on_received(&self) -> u64 {
+        0
+    }
+    /// Returns the number of outcoming carrier errors since the last refresh.
+    fn get_carrier_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+    /// Returns the total number of outcoming carrier errors.
+    fn get_total_carrier_errors_on_transmitted(&self) -> u64 {
+        0
+    }
+    /// Returns the number of collisions since the last refresh.
+    fn get_collisions(&self) -> u64 {
+        0
+    }
+    /// Returns the total number of collisions.
+    fn get_total_collisions(&self) -> u64 {
+        0
+    }
+    /// Returns the number of received multicast packets since the last
+    /// refresh.
+    fn get_multicast(&self) -> u64 {
+        0
+    }
+    /// Returns the total number of received multicast packets.
+    fn get_total_multicast(&self) -> u64 {
+        0
+    }
+    /// Returns the number of received compressed packets since the last
+    /// refresh.
+    fn get_compressed_received(&self) -> u64 {
+        0
+    }
+    /// Returns the total number of received compressed packets.
+    fn get_total_compressed_received(&self) -> u64 {
+        0
+    }
+    /// Returns the number of transmitted compressed packets since the last
+    /// refresh.
+    fn get_compressed_transmitted(&self) -> u64 {
+        0
+    }
+    /// Returns the total number of transmitted compressed packets.
+    fn get_total_compressed_transmitted(&self) -> u64 {
+        0
+    }
+}